@@ -21,4 +21,5 @@ pub mod name_generator;
 
 pub use name_generator::RandomNameGenerator;
 pub use name_generator::RandomName;
+pub use name_generator::NameStream;
 pub use name_generator::Gender;
\ No newline at end of file
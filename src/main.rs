@@ -35,8 +35,8 @@ fn main() {
 
     if valid_commands.contains(&command) {
         let res = match command {
-            "-m"|"--male" => generate_specific(Gender::Male, args, rng),
-            "-f"|"--female" => generate_specific(Gender::Female, args, rng),
+            "-m"|"--male" => generate_specific("m".parse().unwrap(), args, rng),
+            "-f"|"--female" => generate_specific("f".parse().unwrap(), args, rng),
             "-M"|"--many" => generate_many(args, rng),
             "-F"|"--family" => generate_family(args, rng),
             "-h"|"--help" => {
@@ -57,21 +57,14 @@ fn main() {
     }
 }
 
-fn generate_specific(gender: Gender, args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &str> {
+fn generate_specific(gender: Gender, args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &'static str> {
     match args.len() {
         1 => println!("{}", rng.generate_specific(gender)),
         2 => {
             match args[1].parse::<u32>() {
                 Ok(amount) => {
-                    let random_names = match gender {
-                        Gender::Male => rng.generate_many_specific(amount, 0),
-                        Gender::Female => rng.generate_many_specific(0, amount),
-                    };
-                    match random_names {
-                        Some(random_names) => {
-                            for name in random_names { println!("{}", name); }
-                        }
-                        None => return Ok(()),
+                    for name in rng.iter_specific(gender).take(amount as usize) {
+                        println!("{}", name);
                     }
                 },
                 Err(_) => return Err("Could not parse the amount of names to be generated.")
@@ -83,7 +76,7 @@ fn generate_specific(gender: Gender, args: Vec<String>, rng: RandomNameGenerator
     Ok(())
 }
 
-fn generate_many(args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &str> {
+fn generate_many(args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &'static str> {
     match args.len() {
         0..=1 => return Err("Too few command arguments."),
         2 => {
@@ -115,13 +108,12 @@ fn generate_many(args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &str
             }
         }
         4.. => return Err("Too many command arguments."),
-        _ => panic!("Should not reach here.")
     }
 
     Ok(())
 }
 
-fn generate_family(args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &str> {
+fn generate_family(args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &'static str> {
     match args.len() {
         0..=1 => return Err("Too few command arguments."),
         2 => {
@@ -147,7 +139,6 @@ fn generate_family(args: Vec<String>, rng: RandomNameGenerator) -> Result<(), &s
             }
         }
         4.. => return Err("Too many command arguments."),
-        _ => panic!("Should not reach here.")
     }
 
     Ok(())
@@ -1,21 +1,221 @@
 use rand::Rng;
+use serde::Deserialize;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::cell::Cell;
 use std::fmt;
-
-/// Determines if the first name of the random name is
-/// masculine or feminine.
-#[derive(PartialEq)]
+use std::io::{self, BufRead, Read};
+use std::str::FromStr;
+
+/// Determines which first name bank a random name is drawn from.
+/// `NonBinary`, `Other`, and `Unknown` draw uniformly from both the male
+/// and female banks, since the bundled and custom word lists only carry
+/// two gendered pools.
+#[cfg_attr(feature = "serde", doc = r#"
+
+With the `serde` feature enabled, round-trips through `serde_json`:
+```
+use name_maker::Gender;
+
+let json = serde_json::to_string(&Gender::Male).unwrap();
+let round_tripped: Gender = serde_json::from_str(&json).unwrap();
+
+assert_eq!(Gender::Male, round_tripped);
+```
+"#)]
+#[derive(PartialEq, Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Gender {
     Male,
-    Female
+    Female,
+    NonBinary,
+    Other,
+    Unknown
+}
+
+impl fmt::Display for Gender {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Gender::Male => "M",
+            Gender::Female => "F",
+            Gender::NonBinary => "N",
+            Gender::Other => "O",
+            Gender::Unknown => "U"
+        };
+
+        write!(f, "{code}")
+    }
+}
+
+impl FromStr for Gender {
+    type Err = String;
+
+    /// Parses a gender from a single-letter code (`"M"`, `"F"`, `"N"`,
+    /// `"O"`, `"U"`) or its full name (`"male"`, `"female"`, ...),
+    /// case-insensitively. Any other token is rejected.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::Gender;
+    /// use std::str::FromStr;
+    ///
+    /// assert_eq!(Gender::Male, Gender::from_str("m").unwrap());
+    /// assert!(Gender::from_str("xyz").is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Gender, String> {
+        match s.to_ascii_lowercase().as_str() {
+            "m" | "male" => Ok(Gender::Male),
+            "f" | "female" => Ok(Gender::Female),
+            "n" | "nonbinary" | "non-binary" => Ok(Gender::NonBinary),
+            "o" | "other" => Ok(Gender::Other),
+            "u" | "unknown" => Ok(Gender::Unknown),
+            _ => Err(format!("'{s}' is not a recognized gender"))
+        }
+    }
+}
+
+/// A small, non-cryptographic PRNG (SplitMix64) used to back seeded
+/// generators. Its state is held in a `Cell` so that it can be advanced
+/// from `&self` methods on [`RandomNameGenerator`].
+struct SeededRng {
+    state: Cell<u64>,
+}
+
+impl SeededRng {
+    fn new(seed: u64) -> SeededRng {
+        SeededRng { state: Cell::new(seed) }
+    }
+
+    fn next_u64(&self) -> u64 {
+        let state = self.state.get().wrapping_add(0x2d35_8dcc_aa6c_78a5);
+        self.state.set(state);
+
+        let z = state;
+        let z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..len` via multiply-high reduction.
+    fn gen_range(&self, len: usize) -> usize {
+        ((self.next_u64() as u128 * len as u128) >> 64) as usize
+    }
+
+    fn next_bool(&self) -> bool {
+        self.next_u64() & 1 == 0
+    }
+}
+
+/// The source of randomness backing a [`RandomNameGenerator`]. Generators
+/// created with [`RandomNameGenerator::init`] draw from the thread-local
+/// `rand` generator; generators created with
+/// [`RandomNameGenerator::init_with_seed`] draw from a seeded, reproducible
+/// PRNG instead.
+enum RngSource {
+    ThreadRng,
+    Seeded(SeededRng),
+}
+
+impl RngSource {
+    fn rand_index(&self, len: usize) -> usize {
+        match self {
+            RngSource::ThreadRng => rand::thread_rng().gen_range(0..len),
+            RngSource::Seeded(rng) => rng.gen_range(len),
+        }
+    }
+
+    fn rand_bool(&self) -> bool {
+        match self {
+            RngSource::ThreadRng => rand::random(),
+            RngSource::Seeded(rng) => rng.next_bool(),
+        }
+    }
+}
+
+/// A single entry in a [`NameBank`]: a name plus an optional secondary
+/// reading (e.g. a phonetic spelling or romanization), as found in locale
+/// data loaded via [`RandomNameGenerator::init_locale`].
+struct NameBankEntry {
+    name: String,
+    reading: Option<String>
+}
+
+/// A single name as it appears in an embedded locale data file: either a
+/// plain string, or an object carrying a secondary reading alongside the
+/// name (see [`RandomNameGenerator::init_locale`]).
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NameEntry {
+    Plain(String),
+    WithReading { name: String, reading: String }
+}
+
+impl NameEntry {
+    fn into_bank_entry(self) -> NameBankEntry {
+        match self {
+            NameEntry::Plain(name) => NameBankEntry { name, reading: None },
+            NameEntry::WithReading { name, reading } => NameBankEntry { name, reading: Some(reading) }
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct FirstNames {
+    male: Vec<NameEntry>,
+    female: Vec<NameEntry>
+}
+
+/// The shape of an embedded locale data file consumed by
+/// [`RandomNameGenerator::init_locale`].
+#[derive(Deserialize)]
+struct LocaleData {
+    first_name: FirstNames,
+    last_name: Vec<NameEntry>
 }
 
 /// Stores a list of names with similar type (e.g. all are first names or all
 /// last names).
-pub struct NameBank<'a> {
-    bank: Vec<&'a str>,
+pub struct NameBank {
+    bank: Vec<NameBankEntry>,
     len: usize
 }
 
+impl NameBank {
+    /// Builds a bank from already-owned names, e.g. a custom or localized
+    /// word list supplied by the caller. None of these names carry a
+    /// secondary reading.
+    fn from_names(names: Vec<String>) -> NameBank {
+        NameBank::from_entries(
+            names.into_iter().map(|name| NameBankEntry { name, reading: None }).collect()
+        )
+    }
+
+    fn from_entries(bank: Vec<NameBankEntry>) -> NameBank {
+        let len = bank.len();
+
+        NameBank { bank, len }
+    }
+
+    /// Builds a bank from a newline-delimited block of text, trimming each
+    /// line. Used to parse the bundled `include_str!` word lists.
+    fn from_text(raw: &str) -> NameBank {
+        NameBank::from_names(raw.lines().map(|name| name.trim().to_string()).collect())
+    }
+
+    /// Builds a bank by reading newline-delimited names from `reader`, e.g.
+    /// a `File` or any other `Read` implementation.
+    fn from_reader<R: Read>(reader: R) -> io::Result<NameBank> {
+        let mut bank = Vec::new();
+
+        for line in io::BufReader::new(reader).lines() {
+            bank.push(line?.trim().to_string());
+        }
+
+        Ok(NameBank::from_names(bank))
+    }
+}
+
 /// Contains the list of first names (separated by gender) and last names (surnames).
 /// It also contains methods for generating random name/s.
 /// 
@@ -47,70 +247,274 @@ pub struct NameBank<'a> {
 /// // in the family. In this example, the family have 5 boys and 1 girl.
 /// let good_luck_courting_her = rng.generate_family_specific(5, 1);
 /// ```
-pub struct RandomNameGenerator<'a> {
-    male_first_names: NameBank<'a>,
-    female_first_names: NameBank<'a>,
-    last_names: NameBank<'a>
+pub struct RandomNameGenerator {
+    male_first_names: NameBank,
+    female_first_names: NameBank,
+    last_names: NameBank,
+    titles: Option<NameBank>,
+    nicknames: Option<NameBank>,
+    rng: RngSource
 }
 
-impl<'a> RandomNameGenerator<'a> {
+impl RandomNameGenerator {
     /// Initializes the vectors that contain the names needed by the library.
-    pub fn init() -> RandomNameGenerator<'a> {
-        let mut male_first_names_bank: Vec<&str> = Vec::new();
-        let male_first_names_raw: &'static str = include_str!("male_first_names.txt");
-
-        for male_first_name in male_first_names_raw.lines() {
-            male_first_names_bank.push(male_first_name.trim());
+    pub fn init() -> RandomNameGenerator {
+        let male_first_names = NameBank::from_text(include_str!("male_first_names.txt"));
+        let female_first_names = NameBank::from_text(include_str!("female_first_names.txt"));
+        let last_names = NameBank::from_text(include_str!("last_names.txt"));
+
+        RandomNameGenerator {
+            male_first_names, female_first_names, last_names,
+            titles: None, nicknames: None,
+            rng: RngSource::ThreadRng
         }
+    }
 
-        let len = male_first_names_bank.len();
+    /// Builds a generator from caller-supplied word lists instead of the
+    /// bundled ones, e.g. a localized or themed name pool. All of the
+    /// `generate_*` methods work the same as with [`RandomNameGenerator::init`].
+    ///
+    /// `male_first_names`, `female_first_names`, and `last_names` must all
+    /// be non-empty, since every `generate_*`/`iter*` method may draw from
+    /// any of them; an empty bank would otherwise panic on the first draw.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::RandomNameGenerator;
+    ///
+    /// let err = RandomNameGenerator::from_banks(
+    ///     vec![],
+    ///     vec!["Ann".to_string()],
+    ///     vec!["Lee".to_string()]
+    /// );
+    ///
+    /// assert!(err.is_err());
+    /// ```
+    pub fn from_banks(
+        male_first_names: Vec<String>,
+        female_first_names: Vec<String>,
+        last_names: Vec<String>
+    ) -> Result<RandomNameGenerator, String> {
+        Self::require_non_empty("male_first_names", &male_first_names)?;
+        Self::require_non_empty("female_first_names", &female_first_names)?;
+        Self::require_non_empty("last_names", &last_names)?;
+
+        Ok(RandomNameGenerator {
+            male_first_names: NameBank::from_names(male_first_names),
+            female_first_names: NameBank::from_names(female_first_names),
+            last_names: NameBank::from_names(last_names),
+            titles: None, nicknames: None,
+            rng: RngSource::ThreadRng
+        })
+    }
 
-        let male_first_names = NameBank { 
-            bank: male_first_names_bank, len 
-        };
+    /// Builds a generator by reading newline-delimited word lists from the
+    /// given readers, e.g. opened `File`s. Returns an `io::Error` if any
+    /// reader fails, or if `male_first_names`, `female_first_names`, or
+    /// `last_names` turns out to be empty, since every `generate_*`/`iter*`
+    /// method may draw from any of them.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::RandomNameGenerator;
+    /// use std::io::Cursor;
+    ///
+    /// let err = RandomNameGenerator::from_readers(
+    ///     Cursor::new(""),
+    ///     Cursor::new("Ann\n"),
+    ///     Cursor::new("Lee\n")
+    /// );
+    ///
+    /// assert!(err.is_err());
+    /// ```
+    pub fn from_readers<R: Read>(
+        male_first_names: R,
+        female_first_names: R,
+        last_names: R
+    ) -> io::Result<RandomNameGenerator> {
+        let male_first_names = NameBank::from_reader(male_first_names)?;
+        let female_first_names = NameBank::from_reader(female_first_names)?;
+        let last_names = NameBank::from_reader(last_names)?;
+
+        if male_first_names.len == 0 || female_first_names.len == 0 || last_names.len == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "male_first_names, female_first_names, and last_names must all be non-empty"
+            ));
+        }
 
-        let mut female_first_names_bank: Vec<&str> = Vec::new();
-        let female_first_names_raw: &'static str = include_str!("female_first_names.txt");
+        Ok(RandomNameGenerator {
+            male_first_names, female_first_names, last_names,
+            titles: None, nicknames: None,
+            rng: RngSource::ThreadRng
+        })
+    }
 
-        for female_first_name in female_first_names_raw.lines() {
-            female_first_names_bank.push(female_first_name.trim());
+    fn require_non_empty(label: &str, names: &[String]) -> Result<(), String> {
+        if names.is_empty() {
+            return Err(format!("{label} must not be empty"));
         }
 
-        let len = female_first_names_bank.len();
+        Ok(())
+    }
 
-        let female_first_names = NameBank { 
-            bank: female_first_names_bank, len 
-        };
+    /// Attaches a bank of titles (e.g. `"Dr."`, `"Captain"`) for use by
+    /// [`RandomNameGenerator::generate_with_pattern`] patterns containing a
+    /// `{title}` slot.
+    pub fn with_titles(mut self, titles: Vec<String>) -> RandomNameGenerator {
+        self.titles = Some(NameBank::from_names(titles));
 
-        let mut last_names_bank: Vec<&str> = Vec::new();
-        let last_names_raw: &'static str = include_str!("last_names.txt");
+        self
+    }
 
-        for last_name in last_names_raw.lines() {
-            last_names_bank.push(last_name.trim());
-        }
+    /// Attaches a bank of nicknames for use by
+    /// [`RandomNameGenerator::generate_with_pattern`] patterns containing a
+    /// `{nickname}` slot.
+    pub fn with_nicknames(mut self, nicknames: Vec<String>) -> RandomNameGenerator {
+        self.nicknames = Some(NameBank::from_names(nicknames));
 
-        let len = last_names_bank.len();
+        self
+    }
 
-        let last_names = NameBank { 
-            bank: last_names_bank, len 
-        };
+    /// Overrides this generator's source of randomness with a seeded,
+    /// reproducible PRNG, the same one used by
+    /// [`RandomNameGenerator::init_with_seed`]. Two generators built from
+    /// the same banks and the same seed produce identical output across
+    /// all `generate_*` methods. Unlike `init_with_seed`, this can be
+    /// chained onto any constructor — [`RandomNameGenerator::from_banks`],
+    /// [`RandomNameGenerator::from_readers`], or
+    /// [`RandomNameGenerator::init_locale`] — so custom or locale-backed
+    /// generators can be made deterministic too.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::RandomNameGenerator;
+    ///
+    /// let rng1 = RandomNameGenerator::init_locale("ja").unwrap().with_seed(42);
+    /// let rng2 = RandomNameGenerator::init_locale("ja").unwrap().with_seed(42);
+    ///
+    /// assert_eq!(rng1.generate().to_string(), rng2.generate().to_string());
+    /// ```
+    pub fn with_seed(mut self, seed: u64) -> RandomNameGenerator {
+        self.rng = RngSource::Seeded(SeededRng::new(seed));
+
+        self
+    }
+
+    /// Builds a generator from the structured, embedded data file for
+    /// `locale` (e.g. `"en"` or `"ja"`), instead of the flat bundled word
+    /// lists used by [`RandomNameGenerator::init`]. Locale data can attach a
+    /// secondary reading to first names (see [`RandomName::reading`]).
+    /// Returns an error if `locale` is not bundled or its data fails to
+    /// parse.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::RandomNameGenerator;
+    ///
+    /// let rng = RandomNameGenerator::init_locale("ja").unwrap();
+    /// let name = rng.generate();
+    ///
+    /// assert!(name.reading.is_some());
+    /// assert!(name.last_name_reading.is_some());
+    /// ```
+    pub fn init_locale(locale: &str) -> Result<RandomNameGenerator, String> {
+        let raw = Self::locale_raw_data(locale)?;
+
+        let data: LocaleData = serde_json::from_str(raw)
+            .map_err(|e| format!("failed to parse locale data for '{locale}': {e}"))?;
+
+        Ok(RandomNameGenerator {
+            male_first_names: NameBank::from_entries(
+                data.first_name.male.into_iter().map(NameEntry::into_bank_entry).collect()
+            ),
+            female_first_names: NameBank::from_entries(
+                data.first_name.female.into_iter().map(NameEntry::into_bank_entry).collect()
+            ),
+            last_names: NameBank::from_entries(
+                data.last_name.into_iter().map(NameEntry::into_bank_entry).collect()
+            ),
+            titles: None, nicknames: None,
+            rng: RngSource::ThreadRng
+        })
+    }
+
+    fn locale_raw_data(locale: &str) -> Result<&'static str, String> {
+        match locale {
+            "en" => Ok(include_str!("locales/en.json")),
+            "ja" => Ok(include_str!("locales/ja.json")),
+            _ => Err(format!("unknown locale: '{locale}'"))
+        }
+    }
 
-        RandomNameGenerator { male_first_names, female_first_names, last_names }
+    /// Initializes the generator the same way as [`RandomNameGenerator::init`],
+    /// except that names are drawn from a seeded PRNG instead of the
+    /// thread-local one. Two generators created with the same seed produce
+    /// the exact same sequence of names across all `generate_*` methods,
+    /// which makes this constructor suitable for tests, snapshots, and
+    /// reproducible demos.
+    ///
+    /// The guarantee holds across every `generate_*`/`iter*` method, not
+    /// just `generate`.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::{RandomNameGenerator, Gender};
+    ///
+    /// let rng1 = RandomNameGenerator::init_with_seed(42);
+    /// let rng2 = RandomNameGenerator::init_with_seed(42);
+    ///
+    /// assert_eq!(rng1.generate().to_string(), rng2.generate().to_string());
+    ///
+    /// assert_eq!(
+    ///     rng1.generate_specific(Gender::Male).to_string(),
+    ///     rng2.generate_specific(Gender::Male).to_string()
+    /// );
+    ///
+    /// let many1: Vec<_> = rng1.generate_many(3).unwrap().iter().map(ToString::to_string).collect();
+    /// let many2: Vec<_> = rng2.generate_many(3).unwrap().iter().map(ToString::to_string).collect();
+    /// assert_eq!(many1, many2);
+    ///
+    /// let family1: Vec<_> = rng1.generate_family(2).iter().map(ToString::to_string).collect();
+    /// let family2: Vec<_> = rng2.generate_family(2).iter().map(ToString::to_string).collect();
+    /// assert_eq!(family1, family2);
+    ///
+    /// let specific_family1: Vec<_> =
+    ///     rng1.generate_family_specific(1, 2).iter().map(ToString::to_string).collect();
+    /// let specific_family2: Vec<_> =
+    ///     rng2.generate_family_specific(1, 2).iter().map(ToString::to_string).collect();
+    /// assert_eq!(specific_family1, specific_family2);
+    ///
+    /// let iter1: Vec<_> = rng1.iter().take(3).map(|name| name.to_string()).collect();
+    /// let iter2: Vec<_> = rng2.iter().take(3).map(|name| name.to_string()).collect();
+    /// assert_eq!(iter1, iter2);
+    ///
+    /// let iter_specific1: Vec<_> =
+    ///     rng1.iter_specific(Gender::Female).take(3).map(|name| name.to_string()).collect();
+    /// let iter_specific2: Vec<_> =
+    ///     rng2.iter_specific(Gender::Female).take(3).map(|name| name.to_string()).collect();
+    /// assert_eq!(iter_specific1, iter_specific2);
+    /// ```
+    pub fn init_with_seed(seed: u64) -> RandomNameGenerator {
+        Self::init().with_seed(seed)
     }
 
     /// Returns a random name with a random gender.
     pub fn generate(&self) -> RandomName {
-        let gender = Self::get_random_gender();
+        let gender = self.get_random_gender();
 
         self.generate_specific(gender)
     }
 
     /// Returns a random name. Its gender must be specified.
     pub fn generate_specific(&self, gender: Gender) -> RandomName{
-        let first_name = self.generate_first_name_specific(gender);
-        let last_name = self.generate_last_name();
+        let (first_name, reading) = self.generate_first_name_specific(gender);
+        let (last_name, last_name_reading) = self.generate_last_name();
 
-        RandomName { first_name, last_name }
+        RandomName {
+            first_name, last_name, reading, last_name_reading,
+            title: None, middle_name: None, nickname: None, rendered: None
+        }
     }
 
     /// Returns a vector of random names with random genders. If
@@ -152,6 +556,99 @@ impl<'a> RandomNameGenerator<'a> {
         Some(random_names)
     }
 
+    /// Returns an endless iterator of random names with random genders.
+    /// Unlike `generate_many`, this never allocates a `Vec` up front, so it
+    /// composes with adapters like `take`, `filter`, and `collect`.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::RandomNameGenerator;
+    ///
+    /// let rng = RandomNameGenerator::init();
+    /// let names: Vec<_> = rng.iter().take(5).collect();
+    ///
+    /// assert_eq!(5, names.len());
+    /// ```
+    pub fn iter(&self) -> NameStream<'_> {
+        NameStream { generator: self, gender: None }
+    }
+
+    /// Returns an endless iterator of random names constrained to `gender`.
+    pub fn iter_specific(&self, gender: Gender) -> NameStream<'_> {
+        NameStream { generator: self, gender: Some(gender) }
+    }
+
+    /// Returns a name composed from `pattern` with a random gender. See
+    /// [`RandomNameGenerator::generate_with_pattern_specific`] for the
+    /// supported slots.
+    pub fn generate_with_pattern(&self, pattern: &str) -> RandomName {
+        let gender = self.get_random_gender();
+
+        self.generate_with_pattern_specific(pattern, gender)
+    }
+
+    /// Returns a name composed from `pattern`, a format string with slots
+    /// filled in from the relevant banks: `{first}`, `{last}`, `{middle}`
+    /// (drawn from the gendered first-name bank), `{title}`, and
+    /// `{nickname}` (the latter two drawn from the banks attached with
+    /// [`RandomNameGenerator::with_titles`] and
+    /// [`RandomNameGenerator::with_nicknames`]). A slot with no attached
+    /// bank is left unresolved in the output. The returned [`RandomName`]
+    /// carries whichever components were filled, and its `Display` renders
+    /// `pattern` rather than the default `"{first} {last}"`.
+    ///
+    /// Note: this fills slots in a single caller-supplied `pattern`; it does
+    /// not itself pick among several candidate patterns by weight. A caller
+    /// who wants that (e.g. "70% plain, 30% titled") should roll their own
+    /// `match rng.gen_range(0..N)` over a set of patterns and pass the
+    /// chosen one in here — weighted pattern selection is a deliberate scope
+    /// cut from this method, not an oversight.
+    ///
+    /// # Example
+    /// ```
+    /// use name_maker::{RandomNameGenerator, Gender};
+    ///
+    /// let rng = RandomNameGenerator::init()
+    ///     .with_titles(vec!["Dr.".to_string(), "Captain".to_string()]);
+    ///
+    /// let name = rng.generate_with_pattern_specific("{title} {first} {last}", Gender::Male);
+    ///
+    /// assert!(name.title.is_some());
+    /// ```
+    pub fn generate_with_pattern_specific(&self, pattern: &str, gender: Gender) -> RandomName {
+        let (first_name, reading) = self.generate_first_name_specific(gender);
+        let (last_name, last_name_reading) = self.generate_last_name();
+
+        let title = if pattern.contains("{title}") {
+            self.titles.as_ref().filter(|bank| bank.len > 0).map(|bank| self.pick_name(bank))
+        } else {
+            None
+        };
+
+        let middle_name = if pattern.contains("{middle}") {
+            Some(self.generate_first_name_specific(gender).0)
+        } else {
+            None
+        };
+
+        let nickname = if pattern.contains("{nickname}") {
+            self.nicknames.as_ref().filter(|bank| bank.len > 0).map(|bank| self.pick_name(bank))
+        } else {
+            None
+        };
+
+        let mut rendered = pattern.replace("{first}", &first_name).replace("{last}", &last_name);
+
+        if let Some(title) = &title { rendered = rendered.replace("{title}", title); }
+        if let Some(middle_name) = &middle_name { rendered = rendered.replace("{middle}", middle_name); }
+        if let Some(nickname) = &nickname { rendered = rendered.replace("{nickname}", nickname); }
+
+        RandomName {
+            first_name, last_name, reading, last_name_reading, title, middle_name, nickname,
+            rendered: Some(rendered)
+        }
+    }
+
     /// Returns a vector of random names with same last name.
     /// Note that, unlike `generate_many` and `generate_many_specific`, 
     /// `generate_family` does not return `None` even if `children_amount` is initialized
@@ -160,18 +657,24 @@ impl<'a> RandomNameGenerator<'a> {
     pub fn generate_family(&self, children_amount: u32) -> Vec<RandomName> {
         let mut random_family = Vec::new();
 
-        let family_last_name = self.generate_last_name();
+        let (family_last_name, family_last_name_reading) = self.generate_last_name();
+
+        let father = self.generate_family_member(
+            family_last_name.clone(), family_last_name_reading.clone(), Gender::Male
+        );
+        let mother = self.generate_family_member(
+            family_last_name.clone(), family_last_name_reading.clone(), Gender::Female
+        );
 
-        let father = self.generate_family_member(family_last_name.clone(), Gender::Male);
-        let mother = self.generate_family_member(family_last_name.clone(), Gender::Female);
-        
         random_family.push(father);
         random_family.push(mother);
 
         for _ in 0..children_amount {
             let gender = Gender::Male;
-            let child = self.generate_family_member(family_last_name.clone(), gender);
-            
+            let child = self.generate_family_member(
+                family_last_name.clone(), family_last_name_reading.clone(), gender
+            );
+
             random_family.push(child);
         }
 
@@ -188,23 +691,31 @@ impl<'a> RandomNameGenerator<'a> {
     ) -> Vec<RandomName> {
         let mut random_family = Vec::new();
 
-        let family_last_name = self.generate_last_name();
+        let (family_last_name, family_last_name_reading) = self.generate_last_name();
 
-        let father = self.generate_family_member(family_last_name.clone(), Gender::Male);
-        let mother = self.generate_family_member(family_last_name.clone(), Gender::Female);
+        let father = self.generate_family_member(
+            family_last_name.clone(), family_last_name_reading.clone(), Gender::Male
+        );
+        let mother = self.generate_family_member(
+            family_last_name.clone(), family_last_name_reading.clone(), Gender::Female
+        );
 
         random_family.push(father);
         random_family.push(mother);
 
         for _ in 0..male_children_amount {
-            let child = self.generate_family_member(family_last_name.clone(), Gender::Male);
-            
+            let child = self.generate_family_member(
+                family_last_name.clone(), family_last_name_reading.clone(), Gender::Male
+            );
+
             random_family.push(child);
         }
 
         for _ in 0..female_children_amount {
-            let child = self.generate_family_member(family_last_name.clone(), Gender::Female);
-            
+            let child = self.generate_family_member(
+                family_last_name.clone(), family_last_name_reading.clone(), Gender::Female
+            );
+
             random_family.push(child);
         }
 
@@ -229,71 +740,150 @@ impl<'a> RandomNameGenerator<'a> {
         RandomName {
             first_name: "John".to_string(),
             last_name: "Doe".to_string(),
+            reading: None,
+            last_name_reading: None,
+            title: None,
+            middle_name: None,
+            nickname: None,
+            rendered: None
         }
     }
 
-    fn get_random_gender() -> Gender {
-        if rand::random() {
+    fn get_random_gender(&self) -> Gender {
+        if self.rng.rand_bool() {
             Gender::Male
         } else {
             Gender::Female
         }
     }
 
-    fn get_rand_index(len: usize) -> usize {
-        rand::thread_rng().gen_range(0..len)
+    fn get_rand_index(&self, len: usize) -> usize {
+        self.rng.rand_index(len)
     }
 
-    fn generate_first_name_specific(&self, gender: Gender) -> String {
-        let index: usize;
-        let first_name: String;
+    /// Returns a random first name for `gender`, along with its secondary
+    /// reading if the underlying bank carries one (see
+    /// [`RandomNameGenerator::init_locale`]). `Gender::NonBinary`,
+    /// `Gender::Other`, and `Gender::Unknown` draw uniformly from both the
+    /// male and female banks.
+    fn generate_first_name_specific(&self, gender: Gender) -> (String, Option<String>) {
+        let bank = match gender {
+            Gender::Male => &self.male_first_names,
+            Gender::Female => &self.female_first_names,
+            Gender::NonBinary | Gender::Other | Gender::Unknown => {
+                if self.rng.rand_bool() { &self.male_first_names } else { &self.female_first_names }
+            }
+        };
 
-        if gender == Gender::Male {
-            index = Self::get_rand_index(self.male_first_names.len);
-            first_name = self.male_first_names.bank
-                .get(index)
-                .unwrap()
-                .to_string();
-        } else {
-            index = Self::get_rand_index(self.female_first_names.len);
-            first_name = self.female_first_names.bank
-                .get(index)
-                .unwrap()
-                .to_string();
-        }
+        let entry = bank.bank.get(self.get_rand_index(bank.len)).unwrap();
 
-        first_name
+        (entry.name.clone(), entry.reading.clone())
     }
 
-    fn generate_last_name(&self) -> String {
-        let index = Self::get_rand_index(self.last_names.len);
-        let last_name = self.last_names.bank
-            .get(index)
-            .unwrap()
-            .to_string();
+    /// Returns a random last name along with its secondary reading, if the
+    /// underlying bank carries one (see [`RandomNameGenerator::init_locale`]).
+    fn generate_last_name(&self) -> (String, Option<String>) {
+        let entry = self.last_names.bank.get(self.get_rand_index(self.last_names.len)).unwrap();
 
-        last_name
+        (entry.name.clone(), entry.reading.clone())
     }
 
-    fn generate_family_member(&self, family_last_name: String, gender: Gender) -> RandomName {
+    /// Returns a random name from `bank`.
+    fn pick_name(&self, bank: &NameBank) -> String {
+        bank.bank.get(self.get_rand_index(bank.len)).unwrap().name.clone()
+    }
+
+    fn generate_family_member(
+        &self,
+        family_last_name: String,
+        family_last_name_reading: Option<String>,
+        gender: Gender
+    ) -> RandomName {
+        let (first_name, reading) = self.generate_first_name_specific(gender);
+
         RandomName {
-            first_name: self.generate_first_name_specific(gender),
-            last_name: family_last_name
+            first_name, last_name: family_last_name, reading,
+            last_name_reading: family_last_name_reading,
+            title: None, middle_name: None, nickname: None, rendered: None
         }
     }
 }
 
+/// An endless iterator of [`RandomName`]s, created with
+/// [`RandomNameGenerator::iter`] or [`RandomNameGenerator::iter_specific`].
+/// `next` always returns `Some`; bound it with an adapter like `take` when
+/// a finite amount is needed.
+pub struct NameStream<'a> {
+    generator: &'a RandomNameGenerator,
+    gender: Option<Gender>
+}
+
+impl<'a> Iterator for NameStream<'a> {
+    type Item = RandomName;
+
+    fn next(&mut self) -> Option<RandomName> {
+        Some(match self.gender {
+            Some(gender) => self.generator.generate_specific(gender),
+            None => self.generator.generate()
+        })
+    }
+}
 
 /// Contains the first name component and the last name component of a random
 /// name generated by the [`RandomNameGenerator`].
+#[cfg_attr(feature = "serde", doc = r#"
+
+With the `serde` feature enabled, round-trips through `serde_json`:
+```
+use name_maker::RandomNameGenerator;
+
+let name = RandomNameGenerator::generate_default_name();
+let json = serde_json::to_string(&name).unwrap();
+let round_tripped: name_maker::RandomName = serde_json::from_str(&json).unwrap();
+
+assert_eq!(name.first_name, round_tripped.first_name);
+assert_eq!(name.last_name, round_tripped.last_name);
+```
+"#)]
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RandomName {
     pub first_name: String,
-    pub last_name: String
+    pub last_name: String,
+    /// A secondary reading of the first name (e.g. a phonetic spelling or
+    /// romanization), present when the name came from locale data that
+    /// carries one. `None` for names from the bundled flat word lists or
+    /// from `from_banks`/`from_readers`.
+    pub reading: Option<String>,
+    /// A secondary reading of the last name, the counterpart to
+    /// [`RandomName::reading`] for the surname. Present under the same
+    /// conditions.
+    pub last_name_reading: Option<String>,
+    /// A title (e.g. `"Dr."`), present when this name was generated with
+    /// [`RandomNameGenerator::generate_with_pattern`] from a pattern with a
+    /// `{title}` slot and a non-empty bank attached with
+    /// [`RandomNameGenerator::with_titles`].
+    pub title: Option<String>,
+    /// A middle name, present when this name was generated with
+    /// [`RandomNameGenerator::generate_with_pattern`] from a pattern with a
+    /// `{middle}` slot.
+    pub middle_name: Option<String>,
+    /// A nickname, present when this name was generated with
+    /// [`RandomNameGenerator::generate_with_pattern`] from a pattern with a
+    /// `{nickname}` slot and a non-empty bank attached with
+    /// [`RandomNameGenerator::with_nicknames`].
+    pub nickname: Option<String>,
+    /// The pattern-rendered form of this name, set by
+    /// [`RandomNameGenerator::generate_with_pattern`]. When present, this is
+    /// what `Display` prints instead of the default `"{first} {last}"`.
+    rendered: Option<String>
 }
 
 impl fmt::Display for RandomName {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {}", self.first_name, self.last_name)
+        match &self.rendered {
+            Some(rendered) => write!(f, "{rendered}"),
+            None => write!(f, "{} {}", self.first_name, self.last_name)
+        }
     }
 }